@@ -0,0 +1,95 @@
+//! Debounce `N` independent channels (e.g. the buttons of a keypad) in a
+//! single [`update`](DebouncerArray::update) call.
+
+use core::array;
+
+use crate::{DebounceLogic, Edge};
+
+/// Debounces `N` independent channels, such as the rows of a button matrix.
+///
+/// Internally this is just `[D; N]`, where `D` is a [`Debouncer`](crate::Debouncer)
+/// or [`DebouncerStateful`](crate::DebouncerStateful) type (e.g.
+/// `Debouncer<u8, Repeat3>`). That means per-channel RAM consumption is
+/// identical to debouncing each channel separately -- 1 or 2 bytes for a
+/// `Debouncer` and 2 or 4 bytes for a `DebouncerStateful`, depending on the
+/// chosen `debounce_X` repeat count -- while only requiring a single call
+/// site per scan.
+pub struct DebouncerArray<D, const N: usize> {
+    channels: [D; N],
+}
+
+impl<D, const N: usize> DebouncerArray<D, N>
+where
+    D: DebounceLogic,
+{
+    /// Create a new array of debouncers, one per channel, with the given
+    /// initial states. See the crate-level `debounce_*` functions for the
+    /// meaning of `initial_state_pressed`.
+    pub fn new(initial_states_pressed: [bool; N]) -> Self {
+        Self {
+            channels: array::from_fn(|i| D::new(initial_states_pressed[i])),
+        }
+    }
+
+    /// Update every channel and return the resulting edge for each, or `None`
+    /// where the corresponding channel is still bouncing. The returned array
+    /// implements `IntoIterator`, so `array.update(..).into_iter()` is
+    /// already an iterator over this tick's edges; [`iter_high`](Self::iter_high)
+    /// below additionally lets callers query which channels are currently
+    /// debounced high.
+    pub fn update(&mut self, pressed: [bool; N]) -> [Option<Edge>; N] {
+        array::from_fn(|i| self.channels[i].update(pressed[i]))
+    }
+
+    /// Return `true` if channel `i`'s debounced state is logical high.
+    pub fn is_high(&self, i: usize) -> bool {
+        self.channels[i].is_high()
+    }
+
+    /// Return `true` if channel `i`'s debounced state is logical low.
+    pub fn is_low(&self, i: usize) -> bool {
+        self.channels[i].is_low()
+    }
+
+    /// Iterate over the indices of the channels whose debounced state is
+    /// logical high.
+    pub fn iter_high(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..N).filter(move |&i| self.channels[i].is_high())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Debouncer, Repeat2};
+
+    #[test]
+    fn test_independent_channels() {
+        let mut array: DebouncerArray<Debouncer<u8, Repeat2>, 3> =
+            DebouncerArray::new([false, false, true]);
+
+        assert_eq!(array.update([true, false, true]), [None, None, None]);
+        assert!(array.is_high(2));
+        assert!(array.is_low(1));
+
+        // Channel 0 reaches its second consecutive pressed sample and fires;
+        // channel 2 sees its first released sample, which isn't enough yet.
+        assert_eq!(
+            array.update([true, false, false]),
+            [Some(Edge::Rising), None, None]
+        );
+
+        // Channel 2's second consecutive released sample now fires too.
+        assert_eq!(
+            array.update([true, false, false]),
+            [None, None, Some(Edge::Falling)]
+        );
+        assert_eq!(array.iter_high().collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn test_ram_consumption() {
+        let array: DebouncerArray<Debouncer<u8, Repeat2>, 4> = DebouncerArray::new([false; 4]);
+        assert_eq!(std::mem::size_of_val(&array), 4);
+    }
+}
@@ -0,0 +1,240 @@
+//! Async edge-await adapter for executor-based firmware (e.g. Embassy),
+//! built on top of [`DebouncedInputPin`](crate::DebouncedInputPin).
+
+use embedded_hal::digital::InputPin;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+
+use crate::pin::Polarity;
+use crate::{DebounceLogic, DebouncedInputPin, Edge};
+
+/// An async wrapper around a [`DebouncedInputPin`] for executor-based
+/// firmware.
+///
+/// Rather than polling [`update`](crate::Debouncer::update) from a timer
+/// interrupt, a task can `.await` [`wait_for_edge`](Self::wait_for_edge) (or
+/// [`wait_for_high`](Self::wait_for_high) / [`wait_for_low`](Self::wait_for_low)).
+/// While the pin is settled, this awaits the HAL's own
+/// [`Wait::wait_for_any_edge`] instead of burning CPU; once the raw pin
+/// actually moves, it switches to sampling every `poll_interval_us`
+/// microseconds -- using an injected `embedded-hal-async` delay -- through
+/// the same masked bit-shift debouncer the rest of the crate uses, until a
+/// debounced edge settles out.
+pub struct DebouncedPin<T, D, A, Dl> {
+    inner: DebouncedInputPin<T, D, A>,
+    delay: Dl,
+    poll_interval_us: u32,
+}
+
+impl<T, D, A, Dl> DebouncedPin<T, D, A, Dl>
+where
+    T: InputPin + Wait,
+    D: DebounceLogic,
+    A: Polarity,
+    Dl: DelayNs,
+{
+    /// Wrap `pin`, debouncing it with a freshly constructed debouncer and
+    /// sampling it every `poll_interval_us` microseconds using `delay`.
+    pub fn new(pin: T, initial_state_pressed: bool, delay: Dl, poll_interval_us: u32) -> Self {
+        Self {
+            inner: DebouncedInputPin::new(pin, initial_state_pressed),
+            delay,
+            poll_interval_us,
+        }
+    }
+
+    /// Resolve with the next debounced edge.
+    pub async fn wait_for_edge(&mut self) -> Result<Edge, T::Error> {
+        loop {
+            self.inner.pin_mut().wait_for_any_edge().await?;
+            loop {
+                if let Some(edge) = self.inner.poll()? {
+                    return Ok(edge);
+                }
+                // Once the debounced state has settled to a defined level
+                // without producing an edge -- e.g. a glitch that bounced
+                // back to where it started, or a transition a
+                // `DebouncerStateful` suppressed because it matched the
+                // last reported edge -- stop sampling and re-arm
+                // `wait_for_any_edge` instead of polling an unchanging
+                // reading forever.
+                if self.inner.is_high() || self.inner.is_low() {
+                    break;
+                }
+                self.delay.delay_us(self.poll_interval_us).await;
+            }
+        }
+    }
+
+    /// Resolve once the debounced state is logical high.
+    pub async fn wait_for_high(&mut self) -> Result<(), T::Error> {
+        while !self.inner.is_high() {
+            self.wait_for_edge().await?;
+        }
+        Ok(())
+    }
+
+    /// Resolve once the debounced state is logical low.
+    pub async fn wait_for_low(&mut self) -> Result<(), T::Error> {
+        while !self.inner.is_low() {
+            self.wait_for_edge().await?;
+        }
+        Ok(())
+    }
+
+    /// Release the pin, debouncer and delay wrapped by this adapter.
+    pub fn free(self) -> (T, D, Dl) {
+        let (pin, debouncer) = self.inner.free();
+        (pin, debouncer, self.delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pin::ActiveHigh;
+    use crate::{Debouncer, DebouncerStateful, Repeat2};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::vec::Vec;
+
+    struct MockPin {
+        /// One `(level, edge_pending)` pair per sample. `edge_pending`
+        /// models the HAL's edge-interrupt latch, which `wait_for_any_edge`
+        /// consumes and clears.
+        samples: Vec<(bool, bool)>,
+        idx: usize,
+        /// Number of times `wait_for_any_edge` was awaited, so tests can
+        /// confirm the adapter re-arms it instead of only ever calling it once.
+        any_edge_calls: usize,
+    }
+
+    impl MockPin {
+        fn new(samples: &[(bool, bool)]) -> Self {
+            Self {
+                samples: samples.to_vec(),
+                idx: 0,
+                any_edge_calls: 0,
+            }
+        }
+    }
+
+    impl embedded_hal::digital::ErrorType for MockPin {
+        type Error = core::convert::Infallible;
+    }
+
+    impl InputPin for MockPin {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            let level = self.samples[self.idx].0;
+            if self.idx + 1 < self.samples.len() {
+                self.idx += 1;
+            }
+            Ok(level)
+        }
+
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            Ok(!self.is_high()?)
+        }
+    }
+
+    impl Wait for MockPin {
+        async fn wait_for_high_level(&mut self) -> Result<(), Self::Error> {
+            unimplemented!()
+        }
+
+        async fn wait_for_low_level(&mut self) -> Result<(), Self::Error> {
+            unimplemented!()
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+            unimplemented!()
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+            unimplemented!()
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+            self.any_edge_calls += 1;
+            // Advance to the next sample that was marked as an edge.
+            while self.idx + 1 < self.samples.len() && !self.samples[self.idx].1 {
+                self.idx += 1;
+            }
+            Ok(())
+        }
+    }
+
+    struct NoopDelay;
+
+    impl DelayNs for NoopDelay {
+        async fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    /// A waker that never does anything -- fine here since the mock futures
+    /// never actually pend, so `poll` is never called a second time.
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// Drive a future to completion without pulling in an executor
+    /// dependency, since these futures resolve on the first poll.
+    fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = core::pin::pin!(fut);
+        loop {
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_wait_for_edge() {
+        // Not an edge sample, then an edge arrives; the following two
+        // samples are the debounced rising edge (Repeat2).
+        let pin = MockPin::new(&[(false, false), (true, true), (true, false)]);
+        let mut debounced: DebouncedPin<_, Debouncer<u8, Repeat2>, ActiveHigh, _> =
+            DebouncedPin::new(pin, false, NoopDelay, 0);
+
+        assert_eq!(block_on(debounced.wait_for_edge()), Ok(Edge::Rising));
+    }
+
+    #[test]
+    fn test_wait_for_edge_stateful_glitch_rearms_instead_of_spinning() {
+        // A `DebouncerStateful` backing so a glitch can fully debounce back
+        // to the *same* edge type and get suppressed by `last_edge`.
+        let pin = MockPin::new(&[
+            (true, true),   // raw edge: a real rising edge starts
+            (true, false),  // completes the rising edge (Repeat2)
+            (false, true),  // raw edge: a release glitch starts
+            (true, false),  // bounces back up again
+            (true, false),  // completes a same-type rising edge, suppressed
+            (false, true),  // raw edge: the genuine release that follows
+            (false, false), // completes the real falling edge
+        ]);
+        let mut debounced: DebouncedPin<_, DebouncerStateful<u8, Repeat2>, ActiveHigh, _> =
+            DebouncedPin::new(pin, false, NoopDelay, 0);
+
+        assert_eq!(block_on(debounced.wait_for_edge()), Ok(Edge::Rising));
+        // The suppressed glitch settles fully high without producing an
+        // edge; `wait_for_edge` must not spin on that unchanging reading
+        // forever, but re-arm `wait_for_any_edge` for the real edge after it.
+        assert_eq!(block_on(debounced.wait_for_edge()), Ok(Edge::Falling));
+
+        let (pin, ..) = debounced.free();
+        assert_eq!(pin.any_edge_calls, 3);
+    }
+
+    #[test]
+    fn test_wait_for_high() {
+        let pin = MockPin::new(&[(true, true), (true, false)]);
+        let mut debounced: DebouncedPin<_, Debouncer<u8, Repeat2>, ActiveHigh, _> =
+            DebouncedPin::new(pin, false, NoopDelay, 0);
+
+        assert_eq!(block_on(debounced.wait_for_high()), Ok(()));
+        assert!(debounced.inner.is_high());
+    }
+}
@@ -0,0 +1,157 @@
+//! Higher-level gesture detection (click, double-click, long-press, repeat)
+//! built on top of the [`Edge`](crate::Edge) stream produced by a debouncer.
+
+use crate::Edge;
+
+/// A detected button gesture.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Gesture {
+    /// A single short press and release.
+    Click,
+    /// Two clicks in quick succession.
+    DoubleClick,
+    /// The button has been held down past the long-press threshold.
+    LongPress,
+    /// The button is still held down; emitted every repeat interval after a
+    /// [`LongPress`](Gesture::LongPress).
+    Repeat,
+}
+
+#[derive(Copy, Clone)]
+enum State {
+    Idle,
+    Pressed { since: u32 },
+    WaitDoubleClick { released_at: u32 },
+    HoldOrRepeat { next_repeat_at: u32 },
+}
+
+/// Detects clicks, double-clicks, long-presses and auto-repeat from a stream
+/// of [`Edge`]s produced by a [`Debouncer`](crate::Debouncer) or
+/// [`DebouncerStateful`](crate::DebouncerStateful).
+///
+/// The caller drives the detector with [`update`](Self::update) on every
+/// tick, passing the edge (if any) produced this tick and a monotonically
+/// increasing tick counter `now`. This crate has no notion of wall-clock
+/// time, so `now` and the three thresholds below all share the same
+/// caller-defined tick unit (e.g. milliseconds, or debounce poll periods).
+///
+/// `no_std`-friendly: a `GestureDetector` only holds its three `u32`
+/// thresholds plus the small internal state enum (which carries one more
+/// `u32` timestamp).
+pub struct GestureDetector {
+    state: State,
+    double_click_window: u32,
+    long_press_threshold: u32,
+    repeat_interval: u32,
+}
+
+impl GestureDetector {
+    /// Create a new detector.
+    ///
+    /// * `double_click_window`: max ticks between a release and the next
+    ///   press for the pair to count as a [`DoubleClick`](Gesture::DoubleClick).
+    /// * `long_press_threshold`: ticks the button must be held continuously
+    ///   to trigger [`LongPress`](Gesture::LongPress).
+    /// * `repeat_interval`: ticks between successive
+    ///   [`Repeat`](Gesture::Repeat) events while held past
+    ///   `long_press_threshold`.
+    pub fn new(double_click_window: u32, long_press_threshold: u32, repeat_interval: u32) -> Self {
+        Self {
+            state: State::Idle,
+            double_click_window,
+            long_press_threshold,
+            repeat_interval,
+        }
+    }
+
+    /// Feed the detector this tick's edge (if any) and the current tick
+    /// count, returning a gesture if one was detected.
+    pub fn update(&mut self, edge: Option<Edge>, now: u32) -> Option<Gesture> {
+        match self.state {
+            State::Idle => {
+                if edge == Some(Edge::Rising) {
+                    self.state = State::Pressed { since: now };
+                }
+                None
+            }
+            State::Pressed { since } => {
+                if edge == Some(Edge::Falling) {
+                    self.state = State::WaitDoubleClick { released_at: now };
+                    return None;
+                }
+                if now - since >= self.long_press_threshold {
+                    self.state = State::HoldOrRepeat {
+                        next_repeat_at: now + self.repeat_interval,
+                    };
+                    return Some(Gesture::LongPress);
+                }
+                None
+            }
+            State::WaitDoubleClick { released_at } => {
+                if edge == Some(Edge::Rising) {
+                    return if now - released_at <= self.double_click_window {
+                        self.state = State::Idle;
+                        Some(Gesture::DoubleClick)
+                    } else {
+                        self.state = State::Pressed { since: now };
+                        None
+                    };
+                }
+                if now - released_at > self.double_click_window {
+                    self.state = State::Idle;
+                    return Some(Gesture::Click);
+                }
+                None
+            }
+            State::HoldOrRepeat { next_repeat_at } => {
+                if edge == Some(Edge::Falling) {
+                    self.state = State::Idle;
+                    return None;
+                }
+                if now >= next_repeat_at {
+                    self.state = State::HoldOrRepeat {
+                        next_repeat_at: next_repeat_at + self.repeat_interval,
+                    };
+                    return Some(Gesture::Repeat);
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_click() {
+        let mut gesture = GestureDetector::new(20, 50, 30);
+        assert_eq!(gesture.update(Some(Edge::Rising), 0), None);
+        assert_eq!(gesture.update(Some(Edge::Falling), 10), None);
+        assert_eq!(gesture.update(None, 31), Some(Gesture::Click));
+    }
+
+    #[test]
+    fn test_double_click() {
+        let mut gesture = GestureDetector::new(20, 50, 30);
+        assert_eq!(gesture.update(Some(Edge::Rising), 0), None);
+        assert_eq!(gesture.update(Some(Edge::Falling), 10), None);
+        // The second rising edge within the double-click window resolves
+        // the gesture immediately, back to `Idle`.
+        assert_eq!(gesture.update(Some(Edge::Rising), 20), Some(Gesture::DoubleClick));
+        assert_eq!(gesture.update(Some(Edge::Falling), 25), None);
+    }
+
+    #[test]
+    fn test_long_press_and_repeat() {
+        let mut gesture = GestureDetector::new(20, 50, 30);
+        assert_eq!(gesture.update(Some(Edge::Rising), 0), None);
+        assert_eq!(gesture.update(None, 49), None);
+        assert_eq!(gesture.update(None, 50), Some(Gesture::LongPress));
+        assert_eq!(gesture.update(None, 79), None);
+        assert_eq!(gesture.update(None, 80), Some(Gesture::Repeat));
+        assert_eq!(gesture.update(Some(Edge::Falling), 90), None);
+        assert_eq!(gesture.update(Some(Edge::Rising), 95), None);
+    }
+}
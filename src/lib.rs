@@ -189,6 +189,54 @@
 
 use doc_comment::doc_comment;
 
+#[cfg(feature = "embedded-hal")]
+mod pin;
+
+#[cfg(feature = "embedded-hal")]
+pub use pin::{ActiveHigh, ActiveLow, DebouncedInputPin, Polarity};
+
+// The `async` feature depends on `DebouncedInputPin`, so `Cargo.toml` must
+// declare `async = ["embedded-hal", "embedded-hal-async"]` (a `cfg` comment
+// alone can't enforce that enabling `async` also pulls in `embedded-hal`).
+#[cfg(feature = "async")]
+mod asynch;
+
+#[cfg(feature = "async")]
+pub use asynch::DebouncedPin;
+
+mod array;
+
+pub use array::DebouncerArray;
+
+mod gesture;
+
+pub use gesture::{Gesture, GestureDetector};
+
+/// Enables generic code to construct and drive any [`Debouncer`] or
+/// [`DebouncerStateful`] produced by this crate's `debounce_*` functions,
+/// without needing to know the concrete backing integer type.
+///
+/// This is implemented for every `Debouncer<S, M>` / `DebouncerStateful<S, M>`
+/// pair generated by the crate, so it can't be implemented for other types.
+pub trait DebounceLogic: private::Sealed + Sized {
+    /// Construct a new instance in the given initial state. See the
+    /// crate-level `debounce_*` functions for the non-generic equivalent.
+    fn new(initial_state_pressed: bool) -> Self;
+
+    /// Update the state.
+    fn update(&mut self, pressed: bool) -> Option<Edge>;
+
+    /// Return `true` if the debounced state is logical high.
+    fn is_high(&self) -> bool;
+
+    /// Return `true` if the debounced state is logical low.
+    fn is_low(&self) -> bool;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
 /// A debouncer.
 ///
 /// It wraps a `u8` or `u16`, depending on the number of required consecutive
@@ -332,6 +380,46 @@ macro_rules! impl_logic {
                 self.debouncer.is_low()
             }
         }
+
+        impl private::Sealed for Debouncer<$T, $M> {}
+
+        impl DebounceLogic for Debouncer<$T, $M> {
+            fn new(initial_state_pressed: bool) -> Self {
+                $name(initial_state_pressed)
+            }
+
+            fn update(&mut self, pressed: bool) -> Option<Edge> {
+                self.update(pressed)
+            }
+
+            fn is_high(&self) -> bool {
+                self.is_high()
+            }
+
+            fn is_low(&self) -> bool {
+                self.is_low()
+            }
+        }
+
+        impl private::Sealed for DebouncerStateful<$T, $M> {}
+
+        impl DebounceLogic for DebouncerStateful<$T, $M> {
+            fn new(initial_state_pressed: bool) -> Self {
+                $name_stateful(initial_state_pressed)
+            }
+
+            fn update(&mut self, pressed: bool) -> Option<Edge> {
+                self.update(pressed)
+            }
+
+            fn is_high(&self) -> bool {
+                self.is_high()
+            }
+
+            fn is_low(&self) -> bool {
+                self.is_low()
+            }
+        }
     };
 }
 
@@ -351,6 +439,98 @@ impl_logic!(u16, 14, Repeat14, debounce_14, debounce_stateful_14, 0b0011_1111_11
 impl_logic!(u16, 15, Repeat15, debounce_15, debounce_stateful_15, 0b0111_1111_1111_1111);
 impl_logic!(u16, 16, Repeat16, debounce_16, debounce_stateful_16, 0b1111_1111_1111_1111);
 
+/// Marker type for a [`Debouncer`] that tolerates bounce in the middle of a
+/// transition instead of requiring a clean run of consecutive samples.
+///
+/// This type should not be used directly. Instead, construct a [`Debouncer`]
+/// through [`debounce_tolerant()`].
+pub struct Tolerant;
+
+/// Create a new debouncer that reports an edge as soon as it sees a
+/// stable-released prefix followed by a stable-pressed suffix (or vice
+/// versa) within a fixed 8-sample window, tolerating bounce in between.
+///
+/// Unlike the `debounce_X` family, the sample window is fixed at 8 bits and
+/// is not configurable: the top 2 and bottom 3 bits are required to be
+/// stable while the middle 3 bits are treated as "don't care". This reports a
+/// transition faster and more robustly than [`debounce_8`] in the presence
+/// of bounce that happens mid-transition rather than at the very start or
+/// end of it.
+pub fn debounce_tolerant(initial_state_pressed: bool) -> Debouncer<u8, Tolerant> {
+    Debouncer {
+        state: if initial_state_pressed { 0b1111_1111 } else { 0 },
+        mask: core::marker::PhantomData,
+    }
+}
+
+impl Debouncer<u8, Tolerant> {
+    /// Update the state.
+    ///
+    /// After shifting in `pressed`, this masks the state with
+    /// `0b1100_0111`: if the unmasked top two bits are released and the
+    /// bottom three are pressed (`0b0000_0111`), a rising edge is reported
+    /// and the state is latched fully high; if the top two bits are pressed
+    /// and the bottom three are released (`0b1100_0000`), a falling edge is
+    /// reported and the state is latched fully low. The middle 3 bits are
+    /// ignored, so bounce occurring there does not delay detection.
+    pub fn update(&mut self, pressed: bool) -> Option<Edge> {
+        // If all bits are already 1 or 0 and there was no change,
+        // we can immediately return.
+        if self.state == 0b1111_1111 && pressed {
+            return None;
+        }
+        if self.state == 0 && !pressed {
+            return None;
+        }
+
+        // Update state by shifting in the press state.
+        self.state = (self.state << 1) | (pressed as u8);
+
+        // Check the masked before/after pattern.
+        match self.state & 0b1100_0111 {
+            0b0000_0111 => {
+                self.state = 0b1111_1111;
+                Some(Edge::Rising)
+            }
+            0b1100_0000 => {
+                self.state = 0;
+                Some(Edge::Falling)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return `true` if the debounced state is logical high.
+    pub fn is_high(&self) -> bool {
+        self.state == 0b1111_1111
+    }
+
+    /// Return `true` if the debounced state is logical low.
+    pub fn is_low(&self) -> bool {
+        self.state == 0
+    }
+}
+
+impl private::Sealed for Debouncer<u8, Tolerant> {}
+
+impl DebounceLogic for Debouncer<u8, Tolerant> {
+    fn new(initial_state_pressed: bool) -> Self {
+        debounce_tolerant(initial_state_pressed)
+    }
+
+    fn update(&mut self, pressed: bool) -> Option<Edge> {
+        self.update(pressed)
+    }
+
+    fn is_high(&self) -> bool {
+        self.is_high()
+    }
+
+    fn is_low(&self) -> bool {
+        self.is_low()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -507,4 +687,41 @@ mod tests {
         assert_eq!(debouncer.update(false), Some(Edge::Falling));
 
     }
+
+    #[test]
+    fn test_tolerant_rising_edge() {
+        // Initially not pressed
+        let mut debouncer = debounce_tolerant(false);
+        assert!(debouncer.is_low());
+
+        // A clean run of 3 pressed samples triggers a rising edge, same as
+        // `debounce_3` would, since the masked middle bits don't need to be
+        // pressed.
+        assert_eq!(debouncer.update(true), None);
+        assert_eq!(debouncer.update(true), None);
+        assert_eq!(debouncer.update(true), Some(Edge::Rising));
+        assert!(debouncer.is_high());
+    }
+
+    #[test]
+    fn test_tolerant_ignores_mid_transition_bounce() {
+        // Initially not pressed
+        let mut debouncer = debounce_tolerant(false);
+
+        // Two stable released samples, then bounce, then three stable
+        // pressed samples -- the bounce in between is masked out.
+        assert_eq!(debouncer.update(false), None);
+        assert_eq!(debouncer.update(false), None);
+        assert_eq!(debouncer.update(true), None);
+        assert_eq!(debouncer.update(false), None);
+        assert_eq!(debouncer.update(true), None);
+        assert_eq!(debouncer.update(true), None);
+        assert_eq!(debouncer.update(true), Some(Edge::Rising));
+        assert!(debouncer.is_high());
+    }
+
+    #[test]
+    fn test_tolerant_ram_consumption() {
+        assert_eq!(std::mem::size_of_val(&debounce_tolerant(false)), 1);
+    }
 }
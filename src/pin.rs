@@ -0,0 +1,161 @@
+//! `embedded-hal` [`InputPin`] adapter that debounces the pin's level directly,
+//! so callers don't have to poll the pin and feed a `bool` into a [`Debouncer`]
+//! by hand.
+
+use embedded_hal::digital::InputPin;
+
+use crate::{DebounceLogic, Edge};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::ActiveHigh {}
+    impl Sealed for super::ActiveLow {}
+}
+
+/// Polarity marker: a logical-high pin level counts as "pressed".
+pub struct ActiveHigh;
+
+/// Polarity marker: a logical-low pin level counts as "pressed".
+pub struct ActiveLow;
+
+/// Translates a raw `embedded-hal` pin reading into a "pressed" boolean.
+///
+/// Implemented by [`ActiveHigh`] and [`ActiveLow`]; this trait can't be
+/// implemented for other types.
+pub trait Polarity: private::Sealed {
+    /// Return whether `pin` is currently in the "pressed" state for this polarity.
+    fn is_pressed<T: InputPin>(pin: &mut T) -> Result<bool, T::Error>;
+}
+
+impl Polarity for ActiveHigh {
+    fn is_pressed<T: InputPin>(pin: &mut T) -> Result<bool, T::Error> {
+        pin.is_high()
+    }
+}
+
+impl Polarity for ActiveLow {
+    fn is_pressed<T: InputPin>(pin: &mut T) -> Result<bool, T::Error> {
+        pin.is_low()
+    }
+}
+
+/// A debounced `embedded-hal` [`InputPin`].
+///
+/// It combines an owned `InputPin`, a [`Debouncer`](crate::Debouncer) (or
+/// [`DebouncerStateful`](crate::DebouncerStateful)) `D` and a polarity marker
+/// `A` (either [`ActiveHigh`] or [`ActiveLow`]) selecting whether
+/// [`InputPin::is_high`] or [`InputPin::is_low`] counts as "pressed". Calling
+/// [`poll`](Self::poll) reads the pin, runs it through the debouncer and
+/// returns the resulting edge, if any.
+pub struct DebouncedInputPin<T, D, A> {
+    pin: T,
+    debouncer: D,
+    polarity: core::marker::PhantomData<A>,
+}
+
+impl<T, D, A> DebouncedInputPin<T, D, A>
+where
+    T: InputPin,
+    D: DebounceLogic,
+    A: Polarity,
+{
+    /// Wrap `pin`, debouncing its readings with a freshly constructed debouncer.
+    ///
+    /// `initial_state_pressed` is forwarded to the debouncer, see the
+    /// crate-level `debounce_*` functions.
+    pub fn new(pin: T, initial_state_pressed: bool) -> Self {
+        Self {
+            pin,
+            debouncer: D::new(initial_state_pressed),
+            polarity: core::marker::PhantomData,
+        }
+    }
+
+    /// Read the pin, debounce it and return the resulting edge, if any.
+    pub fn poll(&mut self) -> Result<Option<Edge>, T::Error> {
+        let pressed = A::is_pressed(&mut self.pin)?;
+        Ok(self.debouncer.update(pressed))
+    }
+
+    /// Return `true` if the debounced state is "pressed".
+    pub fn is_high(&self) -> bool {
+        self.debouncer.is_high()
+    }
+
+    /// Return `true` if the debounced state is "released".
+    pub fn is_low(&self) -> bool {
+        self.debouncer.is_low()
+    }
+
+    /// Release the pin and debouncer wrapped by this adapter.
+    pub fn free(self) -> (T, D) {
+        (self.pin, self.debouncer)
+    }
+
+    /// Borrow the underlying pin directly, e.g. to drive an
+    /// `embedded-hal-async` trait that isn't exposed through this adapter.
+    pub fn pin_mut(&mut self) -> &mut T {
+        &mut self.pin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Debouncer, Repeat2};
+
+    struct MockPin {
+        levels: std::vec::Vec<bool>,
+        idx: usize,
+    }
+
+    impl MockPin {
+        fn new(levels: &[bool]) -> Self {
+            Self {
+                levels: levels.to_vec(),
+                idx: 0,
+            }
+        }
+    }
+
+    impl embedded_hal::digital::ErrorType for MockPin {
+        type Error = core::convert::Infallible;
+    }
+
+    impl InputPin for MockPin {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            let level = self.levels[self.idx];
+            self.idx += 1;
+            Ok(level)
+        }
+
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            Ok(!self.is_high()?)
+        }
+    }
+
+    #[test]
+    fn test_active_high_poll() {
+        let pin = MockPin::new(&[false, true, true]);
+        let mut debounced: DebouncedInputPin<_, Debouncer<u8, Repeat2>, ActiveHigh> =
+            DebouncedInputPin::new(pin, false);
+
+        assert_eq!(debounced.poll(), Ok(None));
+        assert_eq!(debounced.poll(), Ok(None));
+        assert_eq!(debounced.poll(), Ok(Some(Edge::Rising)));
+        assert!(debounced.is_high());
+    }
+
+    #[test]
+    fn test_active_low_polarity() {
+        // Active-low: a logical-low pin level counts as "pressed".
+        let pin = MockPin::new(&[true, false, false]);
+        let mut debounced: DebouncedInputPin<_, Debouncer<u8, Repeat2>, ActiveLow> =
+            DebouncedInputPin::new(pin, false);
+
+        assert_eq!(debounced.poll(), Ok(None));
+        assert_eq!(debounced.poll(), Ok(None));
+        assert_eq!(debounced.poll(), Ok(Some(Edge::Rising)));
+        assert!(debounced.is_high());
+    }
+}